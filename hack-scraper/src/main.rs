@@ -1,6 +1,15 @@
+extern crate csv;
 extern crate reqwest;
-#[macro_use] extern crate prettytable;
-use prettytable::Table;
+extern crate scraper;
+extern crate serde_json;
+#[macro_use]
+extern crate prettytable;
+#[macro_use]
+extern crate serde_derive;
+
+mod output;
+
+use output::{render, OutputFormat, StoryRecord};
 use scraper::{Html, Selector};
 
 fn get_hacker_news_data() -> Result<String, Box<dyn std::error::Error>> {
@@ -10,14 +19,11 @@ fn get_hacker_news_data() -> Result<String, Box<dyn std::error::Error>> {
     Ok(hn_txt)
 }
 
-fn main() {
-    let hn_txt = get_hacker_news_data().unwrap();
-
-    let document = Html::parse_document(&hn_txt);
-
+fn collect_stories(hn_txt: &str) -> Vec<StoryRecord> {
+    let document = Html::parse_document(hn_txt);
     let stories = Selector::parse("td:nth-child(3) > span > a").unwrap();
 
-    let mut table = Table::new();
+    let mut records = Vec::new();
 
     for story in document.select(&stories) {
         let story_link = story.value().attr("href").unwrap();
@@ -27,9 +33,19 @@ fn main() {
             continue;
         }
 
-        table.add_row(row![FdBybl->story_txt[0]]);
-        table.add_row(row![Fy->story_link]);
+        records.push(StoryRecord {
+            rank: records.len() + 1,
+            title: story_txt[0].to_string(),
+            url: story_link.to_string(),
+        });
     }
 
-    table.printstd();
+    records
+}
+
+fn main() {
+    let hn_txt = get_hacker_news_data().unwrap();
+    let records = collect_stories(&hn_txt);
+
+    render(&records, OutputFormat::Table).unwrap();
 }
@@ -0,0 +1,51 @@
+use prettytable::Table;
+use std::io;
+
+/// One scraped Hacker News front-page entry.
+#[derive(Serialize)]
+pub struct StoryRecord {
+    pub rank: usize,
+    pub title: String,
+    pub url: String,
+}
+
+/// How `render` should serialize a batch of `StoryRecord`s: `Json` and
+/// `Ndjson` for feeding a pipeline, `Csv` for a spreadsheet, `Table` for
+/// the colored terminal view this crate started with.
+pub enum OutputFormat {
+    Json,
+    Ndjson,
+    Table,
+    Csv,
+}
+
+/// Emits `records` to stdout in `format`.
+pub fn render(records: &[StoryRecord], format: OutputFormat) -> Result<(), Box<dyn std::error::Error>> {
+    match format {
+        OutputFormat::Json => {
+            println!("{}", serde_json::to_string_pretty(records)?);
+        }
+        OutputFormat::Ndjson => {
+            for record in records {
+                println!("{}", serde_json::to_string(record)?);
+            }
+        }
+        OutputFormat::Table => {
+            let mut table = Table::new();
+            for record in records {
+                table.add_row(row![FdBybl->record.title]);
+                table.add_row(row![Fy->record.url]);
+            }
+            table.printstd();
+        }
+        OutputFormat::Csv => {
+            let mut writer = csv::Writer::from_writer(io::stdout());
+            for record in records {
+                writer.serialize(record)?;
+            }
+            writer.flush()?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,9 @@
+extern crate scrape_with_rust;
+
+use scrape_with_rust::{scrape, Fetcher};
+
+fn main() {
+    let fetcher = Fetcher::default();
+    let stories = scrape("https://news.ycombinator.com", &fetcher).unwrap();
+    println!("{:#}", stories);
+}
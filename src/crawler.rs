@@ -0,0 +1,194 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use url::Url;
+
+/// A per-host token bucket: `capacity` tokens refilling at `rate` tokens
+/// per second, capped so a burst of requests can't exceed `capacity`.
+struct Bucket {
+    rate: f64,
+    capacity: f64,
+    tokens: f64,
+    updated_at: Instant,
+}
+
+impl Bucket {
+    fn new(rate: f64, capacity: f64) -> Self {
+        Bucket {
+            rate,
+            capacity,
+            tokens: capacity,
+            updated_at: Instant::now(),
+        }
+    }
+
+    fn refill(&mut self) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.updated_at).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.rate).min(self.capacity);
+        self.updated_at = now;
+    }
+
+    /// Blocks the calling thread until a token is available, then takes it.
+    fn take(&mut self) {
+        loop {
+            self.refill();
+            if self.tokens >= 1.0 {
+                self.tokens -= 1.0;
+                return;
+            }
+            let deficit = 1.0 - self.tokens;
+            let wait = Duration::from_secs_f64(deficit / self.rate);
+            thread::sleep(wait);
+        }
+    }
+}
+
+/// Caps how many requests to one host may be in flight at the same
+/// time, on top of the token bucket's rate limit.
+struct ConcurrencyLimit {
+    max: usize,
+    in_flight: Mutex<usize>,
+}
+
+impl ConcurrencyLimit {
+    fn new(max: usize) -> Self {
+        ConcurrencyLimit {
+            max,
+            in_flight: Mutex::new(0),
+        }
+    }
+
+    /// Blocks until fewer than `max` requests to this host are in
+    /// flight, then reserves a slot.
+    fn acquire(&self) {
+        loop {
+            let mut in_flight = self.in_flight.lock().unwrap();
+            if *in_flight < self.max {
+                *in_flight += 1;
+                return;
+            }
+            drop(in_flight);
+            thread::yield_now();
+        }
+    }
+
+    fn release(&self) {
+        *self.in_flight.lock().unwrap() -= 1;
+    }
+}
+
+/// The rate limiter and concurrency cap for one host, kept behind a
+/// single `Arc` so `Crawler::get` only needs one map lookup per host.
+struct Host {
+    bucket: Mutex<Bucket>,
+    concurrency: ConcurrencyLimit,
+}
+
+/// Wraps outbound HTTP requests with a polite, per-host rate limit and
+/// concurrency cap so batch-scraping many story URLs doesn't hammer a
+/// single server.
+///
+/// Each host gets its own token bucket refilled at `rate` tokens/sec up
+/// to `burst` capacity, plus a cap of `max_concurrent` in-flight
+/// requests; `get` blocks until both allow another request before
+/// sending it.
+pub struct Crawler {
+    rate: f64,
+    burst: f64,
+    max_concurrent: usize,
+    hosts: Mutex<HashMap<String, Arc<Host>>>,
+}
+
+impl Crawler {
+    /// Creates a crawler that allows `rate` requests/sec per host, with
+    /// bursts up to `burst` requests and at most `max_concurrent`
+    /// requests to the same host in flight at once.
+    pub fn new(rate: f64, burst: f64, max_concurrent: usize) -> Self {
+        Crawler {
+            rate,
+            burst,
+            max_concurrent,
+            hosts: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Fetches `url`, blocking until the target host's token bucket and
+    /// concurrency cap both allow it.
+    pub fn get(&self, url: &str) -> Result<reqwest::Response, Box<dyn std::error::Error>> {
+        let parsed = Url::parse(url)?;
+        let hostname = parsed.host_str().unwrap_or_default().to_string();
+
+        // Only the host lookup/insert is done under the shared map
+        // lock; the (possibly slow) token wait happens on that host's
+        // own state so a busy host can't stall requests to others.
+        let host = {
+            let mut hosts = self.hosts.lock().unwrap();
+            hosts
+                .entry(hostname)
+                .or_insert_with(|| {
+                    Arc::new(Host {
+                        bucket: Mutex::new(Bucket::new(self.rate, self.burst)),
+                        concurrency: ConcurrencyLimit::new(self.max_concurrent),
+                    })
+                })
+                .clone()
+        };
+
+        host.bucket.lock().unwrap().take();
+        host.concurrency.acquire();
+        let result = reqwest::get(url);
+        host.concurrency.release();
+
+        Ok(result?)
+    }
+}
+
+impl Default for Crawler {
+    /// One request per second per host, with bursts of up to 3 and at
+    /// most 2 requests to the same host in flight at once.
+    fn default() -> Self {
+        Crawler::new(1.0, 3.0, 2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Bucket;
+    use std::time::Instant;
+
+    #[test]
+    fn take_does_not_block_within_burst_capacity() {
+        let mut bucket = Bucket::new(1.0, 3.0);
+
+        let start = Instant::now();
+        bucket.take();
+        bucket.take();
+        bucket.take();
+        let elapsed = start.elapsed();
+
+        assert!(
+            elapsed.as_millis() < 50,
+            "taking within burst capacity should not sleep, took {:?}",
+            elapsed
+        );
+    }
+
+    #[test]
+    fn take_blocks_once_burst_capacity_is_exhausted() {
+        let mut bucket = Bucket::new(10.0, 1.0);
+
+        bucket.take();
+        let start = Instant::now();
+        bucket.take();
+        let elapsed = start.elapsed();
+
+        assert!(
+            elapsed.as_millis() >= 90,
+            "exhausting the burst should wait ~1/rate seconds, took {:?}",
+            elapsed
+        );
+    }
+}
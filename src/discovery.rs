@@ -0,0 +1,224 @@
+extern crate quick_xml;
+
+use std::collections::HashSet;
+
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+
+use url::Url;
+
+use crawler::Crawler;
+
+/// The result of parsing one sitemap or feed document.
+struct Parsed {
+    /// True if this was a `<sitemapindex>`, meaning `locs` are URLs to
+    /// further sitemaps rather than pages.
+    is_index: bool,
+    locs: Vec<Url>,
+}
+
+/// Fetches `sitemap_url` through `crawler` and recursively follows any
+/// `<sitemapindex>` entries (skipping sitemaps already visited, so a
+/// cyclic or self-referencing sitemap can't recurse forever), returning
+/// the flat list of page/entry URLs found across the whole tree.
+///
+/// Pass the same `crawler` a caller is using to fetch the resulting
+/// pages so its rate limit covers the sitemap/feed fetches too.
+pub fn discover_site(crawler: &Crawler, sitemap_url: &str) -> Result<Vec<Url>, Box<dyn std::error::Error>> {
+    let mut visited = HashSet::new();
+    discover_recursive(crawler, sitemap_url, &mut visited)
+}
+
+fn discover_recursive(
+    crawler: &Crawler,
+    url: &str,
+    visited: &mut HashSet<String>,
+) -> Result<Vec<Url>, Box<dyn std::error::Error>> {
+    if !visited.insert(url.to_string()) {
+        return Ok(Vec::new());
+    }
+
+    let body = crawler.get(url)?.text()?;
+    let parsed = parse(&body);
+
+    if !parsed.is_index {
+        return Ok(parsed.locs);
+    }
+
+    let mut urls = Vec::new();
+    for nested in parsed.locs {
+        urls.extend(discover_recursive(crawler, nested.as_str(), visited)?);
+    }
+    Ok(urls)
+}
+
+/// Parses a sitemap (`<urlset>`/`<sitemapindex>`) or feed (RSS `<item>`
+/// or Atom `<entry>`) document into a flat list of URLs, decoding
+/// `<loc>`, `<link>`, and `<link href="...">` entries.
+///
+/// Atom entries commonly carry several `<link>` elements distinguished
+/// by `rel` (`self`, `edit`, `enclosure`, `alternate`, ...); only ones
+/// with no `rel` or `rel="alternate"` point at the actual content, so
+/// `is_content_link` filters out the rest.
+fn parse(xml: &str) -> Parsed {
+    let mut reader = Reader::from_str(xml);
+    reader.trim_text(true);
+
+    let mut buf = Vec::new();
+    let mut locs = Vec::new();
+    let mut is_index = false;
+    let mut in_loc = false;
+    let mut in_link = false;
+
+    loop {
+        match reader.read_event(&mut buf) {
+            Ok(Event::Start(ref e)) => match e.local_name() {
+                b"sitemapindex" => is_index = true,
+                b"loc" => in_loc = true,
+                b"link" => {
+                    if is_content_link(e) {
+                        in_link = true;
+                        if let Some(href) = attr(e, b"href") {
+                            push_url(&mut locs, &href);
+                        }
+                    }
+                }
+                _ => {}
+            },
+            // Self-closing tags (quick_xml never pairs these with an
+            // `Event::End`) — Atom's `<link href="..."/>` is almost
+            // always written this way, so it has to be handled here too.
+            Ok(Event::Empty(ref e)) => match e.local_name() {
+                b"sitemapindex" => is_index = true,
+                b"link" => {
+                    if is_content_link(e) {
+                        if let Some(href) = attr(e, b"href") {
+                            push_url(&mut locs, &href);
+                        }
+                    }
+                }
+                _ => {}
+            },
+            Ok(Event::Text(ref e)) => {
+                if in_loc || in_link {
+                    if let Ok(text) = e.unescape_and_decode(&reader) {
+                        push_url(&mut locs, &text);
+                    }
+                }
+            }
+            Ok(Event::End(ref e)) => match e.local_name() {
+                b"loc" => in_loc = false,
+                b"link" => in_link = false,
+                _ => {}
+            },
+            Ok(Event::Eof) => break,
+            Err(_) => break,
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    Parsed { is_index, locs }
+}
+
+/// True if a `<link>` element points at the entry's own content rather
+/// than at a `rel="self"`/`"edit"`/`"enclosure"`/... relation.
+fn is_content_link(start: &BytesStart) -> bool {
+    match attr(start, b"rel") {
+        None => true,
+        Some(rel) => rel == "alternate",
+    }
+}
+
+fn attr(start: &BytesStart, name: &[u8]) -> Option<String> {
+    start
+        .attributes()
+        .filter_map(Result::ok)
+        .find(|a| a.key == name)
+        .and_then(|a| a.unescaped_value().ok())
+        .map(|v| String::from_utf8_lossy(&v).into_owned())
+}
+
+fn push_url(urls: &mut Vec<Url>, candidate: &str) {
+    let trimmed = candidate.trim();
+    if trimmed.is_empty() {
+        return;
+    }
+    if let Ok(url) = Url::parse(trimmed) {
+        urls.push(url);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse;
+
+    #[test]
+    fn parses_urlset_locs() {
+        let xml = r#"<?xml version="1.0"?>
+            <urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+                <url><loc>https://example.com/a</loc></url>
+                <url><loc>https://example.com/b</loc></url>
+            </urlset>"#;
+
+        let parsed = parse(xml);
+
+        assert!(!parsed.is_index);
+        assert_eq!(
+            parsed.locs.iter().map(|u| u.as_str()).collect::<Vec<_>>(),
+            vec!["https://example.com/a", "https://example.com/b"]
+        );
+    }
+
+    #[test]
+    fn flags_sitemapindex_and_collects_nested_locs() {
+        let xml = r#"<sitemapindex xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+            <sitemap><loc>https://example.com/sitemap-a.xml</loc></sitemap>
+        </sitemapindex>"#;
+
+        let parsed = parse(xml);
+
+        assert!(parsed.is_index);
+        assert_eq!(parsed.locs[0].as_str(), "https://example.com/sitemap-a.xml");
+    }
+
+    #[test]
+    fn parses_rss_item_links() {
+        let xml = r#"<rss><channel>
+            <item><link>https://example.com/post-1</link></item>
+        </channel></rss>"#;
+
+        let parsed = parse(xml);
+
+        assert_eq!(parsed.locs[0].as_str(), "https://example.com/post-1");
+    }
+
+    #[test]
+    fn parses_atom_self_closing_alternate_link() {
+        let xml = r#"<feed xmlns="http://www.w3.org/2005/Atom">
+            <entry><link rel="alternate" href="https://example.com/post-2"/></entry>
+        </feed>"#;
+
+        let parsed = parse(xml);
+
+        assert_eq!(parsed.locs[0].as_str(), "https://example.com/post-2");
+    }
+
+    #[test]
+    fn ignores_non_content_atom_links() {
+        let xml = r#"<feed xmlns="http://www.w3.org/2005/Atom">
+            <atom:link rel="self" href="https://example.com/feed.xml"/>
+            <entry>
+                <link rel="edit" href="https://example.com/post-3/edit"/>
+                <link rel="alternate" href="https://example.com/post-3"/>
+            </entry>
+        </feed>"#;
+
+        let parsed = parse(xml);
+
+        assert_eq!(
+            parsed.locs.iter().map(|u| u.as_str()).collect::<Vec<_>>(),
+            vec!["https://example.com/post-3"]
+        );
+    }
+}
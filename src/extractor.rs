@@ -0,0 +1,131 @@
+use select::document::Document;
+use serde_json::Value;
+use url::Url;
+
+use extractors::hacker_news::HackerNewsExtractor;
+
+/// A pluggable scraper for one family of sites.
+///
+/// Implementors declare which URLs they handle via `matches` and turn an
+/// already-fetched `Document` into structured JSON via `extract`.
+pub trait Extractor {
+    /// Returns true if this extractor knows how to handle `url`.
+    fn matches(&self, url: &Url) -> bool;
+
+    /// Pulls structured data out of `doc`.
+    fn extract(&self, doc: &Document) -> Result<Value, Box<dyn std::error::Error>>;
+
+    /// Whether this site renders its content client-side, meaning a
+    /// plain HTTP GET won't see it and `scrape` should fetch it through
+    /// a `Fetcher::WebDriver` instead.
+    fn needs_js(&self) -> bool {
+        false
+    }
+
+    /// CSS selector the `WebDriver` fetcher should wait for before
+    /// reading the rendered DOM. Only consulted when `needs_js` is true.
+    fn js_ready_selector(&self) -> &str {
+        ""
+    }
+}
+
+/// Dispatches a URL to the first registered `Extractor` that matches it.
+pub struct Registry {
+    extractors: Vec<Box<dyn Extractor>>,
+}
+
+impl Registry {
+    /// Creates an empty registry with no extractors registered.
+    pub fn new() -> Self {
+        Registry {
+            extractors: Vec::new(),
+        }
+    }
+
+    /// Adds `extractor` to the registry. Extractors are tried in
+    /// registration order, so register more specific sites before
+    /// broader fallbacks.
+    pub fn register(&mut self, extractor: Box<dyn Extractor>) {
+        self.extractors.push(extractor);
+    }
+
+    /// Returns the first registered extractor whose `matches(url)` is true.
+    pub fn find(&self, url: &Url) -> Option<&dyn Extractor> {
+        self.extractors
+            .iter()
+            .map(|e| e.as_ref())
+            .find(|e| e.matches(url))
+    }
+
+    /// Runs `doc` through the first extractor whose `matches(url)` is true.
+    pub fn extract(&self, url: &Url, doc: &Document) -> Result<Value, Box<dyn std::error::Error>> {
+        self.find(url)
+            .ok_or_else(|| format!("no extractor registered for {}", url).into())
+            .and_then(|extractor| extractor.extract(doc))
+    }
+}
+
+impl Default for Registry {
+    /// A registry pre-populated with the extractors this crate ships.
+    /// Add your own with `register` for additional sites.
+    fn default() -> Self {
+        let mut registry = Registry::new();
+        registry.register(Box::new(HackerNewsExtractor));
+        registry
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Extractor, Registry};
+    use select::document::Document;
+    use serde_json::{json, Value};
+    use url::Url;
+
+    struct StubExtractor {
+        host: &'static str,
+    }
+
+    impl Extractor for StubExtractor {
+        fn matches(&self, url: &Url) -> bool {
+            url.host_str() == Some(self.host)
+        }
+
+        fn extract(&self, _doc: &Document) -> Result<Value, Box<dyn std::error::Error>> {
+            Ok(json!({ "host": self.host }))
+        }
+    }
+
+    #[test]
+    fn find_returns_none_for_an_empty_registry() {
+        let registry = Registry::new();
+        let url = Url::parse("https://example.com").unwrap();
+
+        assert!(registry.find(&url).is_none());
+    }
+
+    #[test]
+    fn register_adds_a_custom_extractor_callers_can_dispatch_to() {
+        let mut registry = Registry::new();
+        registry.register(Box::new(StubExtractor { host: "example.com" }));
+
+        let url = Url::parse("https://example.com/page").unwrap();
+        let doc = Document::from("<html></html>");
+
+        let result = registry.extract(&url, &doc).unwrap();
+        assert_eq!(result, json!({ "host": "example.com" }));
+    }
+
+    #[test]
+    fn extract_dispatches_to_the_extractor_matching_the_url_host() {
+        let mut registry = Registry::new();
+        registry.register(Box::new(StubExtractor { host: "a.com" }));
+        registry.register(Box::new(StubExtractor { host: "b.com" }));
+
+        let url = Url::parse("https://b.com").unwrap();
+        let doc = Document::from("<html></html>");
+
+        let result = registry.extract(&url, &doc).unwrap();
+        assert_eq!(result, json!({ "host": "b.com" }));
+    }
+}
@@ -0,0 +1,41 @@
+use select::document::Document;
+use select::predicate::{Class, Name, Predicate};
+use serde_json::{json, Value};
+use url::Url;
+
+use extractor::Extractor;
+use strip_html::strip_html;
+
+/// Extracts front-page story listings from Hacker News.
+pub struct HackerNewsExtractor;
+
+impl Extractor for HackerNewsExtractor {
+    fn matches(&self, url: &Url) -> bool {
+        url.host_str() == Some("news.ycombinator.com")
+    }
+
+    fn extract(&self, doc: &Document) -> Result<Value, Box<dyn std::error::Error>> {
+        let mut stories = Vec::new();
+
+        for node in doc.find(Class("athing")) {
+            let rank = node
+                .find(Class("rank"))
+                .next()
+                .map(|n| n.text().trim_end_matches('.').to_string())
+                .unwrap_or_default();
+
+            let link = node
+                .find(Class("title").descendant(Name("a")))
+                .next()
+                .ok_or("missing story link")?;
+
+            stories.push(json!({
+                "rank": rank,
+                "title": strip_html(&link.text()),
+                "url": link.attr("href").unwrap_or_default(),
+            }));
+        }
+
+        Ok(Value::Array(stories))
+    }
+}
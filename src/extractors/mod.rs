@@ -0,0 +1 @@
+pub mod hacker_news;
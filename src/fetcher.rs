@@ -0,0 +1,77 @@
+extern crate thirtyfour;
+extern crate tokio;
+
+use thirtyfour::error::WebDriverResult;
+use thirtyfour::{By, DesiredCapabilities, WebDriver};
+
+use std::sync::Arc;
+
+use select::document::Document;
+
+use crawler::Crawler;
+
+/// Default local WebDriver endpoint (e.g. `chromedriver --port=4444`).
+pub const DEFAULT_WEBDRIVER_ENDPOINT: &str = "http://localhost:4444";
+
+/// Fetches a page and hands back a parsed `Document`.
+///
+/// Most sites render server-side and are served fine by `Http`, but
+/// sites that build their DOM client-side need `WebDriver` so the
+/// extractor sees the same markup a browser would.
+///
+/// `Http` holds its `Crawler` behind an `Arc` so a single `Fetcher` can
+/// be cloned and reused across a whole crawl: the underlying token
+/// buckets accumulate state across calls instead of resetting on every
+/// fetch.
+#[derive(Clone)]
+pub enum Fetcher {
+    /// Plain `reqwest` GET, rate-limited through a shared `Crawler`.
+    Http(Arc<Crawler>),
+    /// A headless Chrome/Firefox session driven through WebDriver,
+    /// waiting for `ready_selector` to appear before reading the DOM.
+    WebDriver { endpoint: String, ready_selector: String },
+}
+
+impl Fetcher {
+    /// Fetches `url` and parses the result as an HTML document.
+    pub fn fetch(&self, url: &str) -> Result<Document, Box<dyn std::error::Error>> {
+        match self {
+            Fetcher::Http(crawler) => {
+                let resp = crawler.get(url)?;
+                Ok(Document::from_read(resp)?)
+            }
+            Fetcher::WebDriver { endpoint, ready_selector } => {
+                let html = fetch_rendered(endpoint, url, ready_selector)?;
+                Ok(Document::from(html.as_str()))
+            }
+        }
+    }
+}
+
+impl Default for Fetcher {
+    fn default() -> Self {
+        Fetcher::Http(Arc::new(Crawler::default()))
+    }
+}
+
+fn fetch_rendered(endpoint: &str, url: &str, ready_selector: &str) -> WebDriverResult<String> {
+    let mut runtime = tokio::runtime::Runtime::new()?;
+
+    runtime.block_on(async {
+        let caps = DesiredCapabilities::chrome();
+        let driver = WebDriver::new(endpoint, &caps).await?;
+
+        // Run the part that can fail (wrong selector, render timeout)
+        // separately so `driver.quit()` still runs on that path instead
+        // of being skipped by the early `?` return.
+        let result: WebDriverResult<String> = async {
+            driver.get(url).await?;
+            driver.query(By::Css(ready_selector)).first().await?;
+            driver.source().await
+        }
+        .await;
+
+        let _ = driver.quit().await;
+        result
+    })
+}
@@ -0,0 +1,88 @@
+extern crate reqwest;
+extern crate select;
+extern crate serde_json;
+extern crate url;
+
+mod crawler;
+mod discovery;
+mod extractor;
+mod extractors;
+mod fetcher;
+mod readability;
+mod strip_html;
+
+pub use crawler::Crawler;
+pub use discovery::discover_site;
+pub use extractor::{Extractor, Registry};
+pub use fetcher::Fetcher;
+pub use readability::Article;
+pub use strip_html::strip_html;
+
+use std::sync::Arc;
+
+use url::Url;
+
+/// Fetches `url` through `fetcher`, dispatches it to the first
+/// `Extractor` in the default registry whose `matches` returns true, and
+/// returns the extracted data. When that extractor's `needs_js` is true,
+/// `fetcher` is not used: the page is fetched through a WebDriver session
+/// at `DEFAULT_WEBDRIVER_ENDPOINT` waiting for its `js_ready_selector`.
+///
+/// This is the one entry point consumers of the crate need: add a new
+/// site by implementing `Extractor` and registering it in `Registry`,
+/// rather than writing another one-off scraping binary. Pass the same
+/// `fetcher` across a batch of calls so its `Crawler`'s rate limiting
+/// actually accumulates instead of resetting every request.
+pub fn scrape(url: &str, fetcher: &Fetcher) -> Result<serde_json::Value, Box<dyn std::error::Error>> {
+    let parsed = Url::parse(url)?;
+    let registry = Registry::default();
+    let extractor = registry
+        .find(&parsed)
+        .ok_or_else(|| format!("no extractor registered for {}", parsed))?;
+
+    let document = if extractor.needs_js() {
+        Fetcher::WebDriver {
+            endpoint: fetcher::DEFAULT_WEBDRIVER_ENDPOINT.to_string(),
+            ready_selector: extractor.js_ready_selector().to_string(),
+        }
+        .fetch(url)?
+    } else {
+        fetcher.fetch(url)?
+    };
+
+    extractor.extract(&document)
+}
+
+/// Fetches `url` through `fetcher` and extracts its main readable
+/// article body, following text density rather than a site-specific
+/// selector. Useful for pulling the full story out of a link an
+/// extractor like `HackerNewsExtractor` surfaced.
+pub fn read_article(url: &str, fetcher: &Fetcher) -> Result<Article, Box<dyn std::error::Error>> {
+    let document = fetcher.fetch(url)?;
+
+    readability::extract(&document).ok_or_else(|| "no article content found".into())
+}
+
+/// Enumerates every URL reachable from `sitemap_url` and scrapes each one
+/// through the extractor registry, skipping pages no extractor matches
+/// and pages whose fetch or extraction fails.
+///
+/// With an `Http` fetcher, discovery and every resulting scrape share
+/// its `Crawler`, so the rate limit applies across the whole crawl
+/// rather than resetting per page. With a `WebDriver` fetcher, discovery
+/// uses a throwaway `Crawler` (it always needs a plain HTTP GET of the
+/// sitemap/feed XML) and the page fetches go through the WebDriver,
+/// which has no rate limit.
+pub fn scrape_site(sitemap_url: &str, fetcher: &Fetcher) -> Result<Vec<serde_json::Value>, Box<dyn std::error::Error>> {
+    let crawler = match fetcher {
+        Fetcher::Http(crawler) => crawler.clone(),
+        Fetcher::WebDriver { .. } => Arc::new(Crawler::default()),
+    };
+
+    let urls = discover_site(&crawler, sitemap_url)?;
+
+    Ok(urls
+        .iter()
+        .filter_map(|url| scrape(url.as_str(), fetcher).ok())
+        .collect())
+}
@@ -0,0 +1,179 @@
+use select::document::Document;
+use select::node::Node;
+use select::predicate::Name;
+
+/// The main readable content of a page, pulled out of surrounding chrome
+/// (navigation, footers, scripts) by scoring DOM nodes rather than
+/// following site-specific selectors.
+pub struct Article {
+    pub title: String,
+    pub text: String,
+}
+
+const CANDIDATE_TAGS: [&str; 3] = ["p", "div", "article"];
+const STRIP_TAGS: [&str; 4] = ["nav", "footer", "script", "style"];
+
+/// Extracts the main article body from an already-parsed `doc`.
+///
+/// Each `<p>` outside `nav`/`footer`/`script`/`style` is scored by the
+/// length of its text minus the length of the text inside its `<a>`
+/// descendants (boilerplate link lists score low). A paragraph whose
+/// parent is a candidate block element (`p`, `div`, `article`) adds a
+/// quarter of its score to that parent and an eighth to the grandparent
+/// when that is a candidate too, since the real article body is usually
+/// the *container* of the highest-scoring paragraphs rather than a single
+/// paragraph; only a paragraph with no candidate parent keeps a score of
+/// its own. `div` and `article` elements are not scored on their own
+/// text, only through this propagation. The highest-scoring element is
+/// taken as the article body and the text of the `<p>` elements inside
+/// it is joined in document order.
+pub fn extract(doc: &Document) -> Option<Article> {
+    let title = doc
+        .find(Name("title"))
+        .next()
+        .map(|n| n.text())
+        .unwrap_or_default();
+
+    let mut scores: Vec<(usize, f64)> = Vec::new();
+
+    for node in doc.find(Name("p")) {
+        if is_stripped(&node) {
+            continue;
+        }
+
+        let score = score_node(&node);
+        if score <= 0.0 {
+            continue;
+        }
+
+        match node.parent().filter(|parent| is_candidate(parent)) {
+            Some(parent) => {
+                bump(&mut scores, parent.index(), score * 0.25);
+                if let Some(grandparent) = parent.parent() {
+                    if is_candidate(&grandparent) {
+                        bump(&mut scores, grandparent.index(), score * 0.125);
+                    }
+                }
+            }
+            // No candidate container to propagate into: the paragraph
+            // competes on its own score.
+            None => bump(&mut scores, node.index(), score),
+        }
+    }
+
+    let best_index = scores
+        .into_iter()
+        .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+        .map(|(index, _)| index)?;
+
+    let container = doc.nth(best_index)?;
+    let text = collect_text(&container);
+
+    Some(Article { title, text })
+}
+
+fn score_node(node: &Node) -> f64 {
+    let text_len = node.text().len() as f64;
+    let link_len: f64 = node
+        .find(Name("a"))
+        .map(|a| a.text().len() as f64)
+        .sum();
+
+    text_len - link_len
+}
+
+fn is_candidate(node: &Node) -> bool {
+    node.name()
+        .map(|name| CANDIDATE_TAGS.contains(&name))
+        .unwrap_or(false)
+}
+
+fn is_stripped(node: &Node) -> bool {
+    let mut ancestor = node.parent();
+    while let Some(current) = ancestor {
+        if current.name().map(|name| STRIP_TAGS.contains(&name)).unwrap_or(false) {
+            return true;
+        }
+        ancestor = current.parent();
+    }
+    false
+}
+
+fn bump(scores: &mut Vec<(usize, f64)>, index: usize, delta: f64) {
+    if let Some(entry) = scores.iter_mut().find(|(i, _)| *i == index) {
+        entry.1 += delta;
+    } else {
+        scores.push((index, delta));
+    }
+}
+
+fn collect_text(node: &Node) -> String {
+    let mut out = String::new();
+    for child in node.find(Name("p")) {
+        let t = child.text();
+        let t = t.trim();
+        if !t.is_empty() {
+            out.push_str(t);
+            out.push_str("\n\n");
+        }
+    }
+
+    let out = out.trim_end().to_string();
+    if !out.is_empty() {
+        return out;
+    }
+
+    // The winning container can itself be a bare `<p>` (its parent
+    // wasn't a candidate tag, so propagation never ran and its own
+    // score won by default) — `find(Name("p"))` only matches
+    // descendants, so fall back to the container's own text.
+    node.text().trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::extract;
+    use select::document::Document;
+
+    #[test]
+    fn picks_the_article_container_over_a_link_dense_nav() {
+        let html = r#"
+            <html><head><title>My Title</title></head><body>
+            <nav><ul>
+                <li><a href="/a">Link A</a></li>
+                <li><a href="/b">Link B</a></li>
+            </ul></nav>
+            <div>
+                <p>This is a long paragraph of real article content that should
+                score highly because it has lots of text and very few links
+                inside it at all here.</p>
+                <p>Here is a second paragraph continuing the article with even
+                more real substantive text content for scoring purposes.</p>
+            </div>
+            </body></html>
+        "#;
+
+        let doc = Document::from(html);
+        let article = extract(&doc).expect("article should be found");
+
+        assert_eq!(article.title, "My Title");
+        assert!(article.text.contains("long paragraph"));
+        assert!(article.text.contains("second paragraph"));
+        assert!(!article.text.contains("Link A"));
+    }
+
+    #[test]
+    fn falls_back_to_the_bare_p_s_own_text_when_it_has_no_nested_p() {
+        let html = r#"
+            <html><head><title>Solo</title></head><body>
+            <p>A single paragraph with enough real content and no links to
+            speak of whatsoever in this text block.</p>
+            </body></html>
+        "#;
+
+        let doc = Document::from(html);
+        let article = extract(&doc).expect("article should be found");
+
+        assert!(article.text.contains("A single paragraph"));
+    }
+}
@@ -0,0 +1,125 @@
+extern crate quick_xml;
+
+use quick_xml::events::Event;
+use quick_xml::Reader;
+
+/// Strips markup from `input` and returns its text content, decoding
+/// entities like `&nbsp;` and `&amp;` along the way. Each text fragment
+/// is trimmed after decoding and the non-empty ones are joined with a
+/// single space.
+///
+/// This walks `input` as a stream of `Text` events rather than parsing a
+/// full DOM, so it works equally well on a standalone HTML fragment (a
+/// headline's inner markup) as on a whole document, and gives callers a
+/// single normalized string instead of `node.text().collect::<Vec<_>>()`
+/// noise made of nested whitespace fragments.
+pub fn strip_html(input: &str) -> String {
+    let mut reader = Reader::from_str(input);
+    reader.trim_text(true);
+
+    let mut out = String::new();
+    let mut buf = Vec::new();
+
+    loop {
+        match reader.read_event(&mut buf) {
+            Ok(Event::Text(ref e)) | Ok(Event::CData(ref e)) => {
+                let raw = String::from_utf8_lossy(e.escaped());
+                let decoded = decode_entities(&raw);
+                let text = decoded.trim();
+                if !out.is_empty() && !text.is_empty() {
+                    out.push(' ');
+                }
+                out.push_str(text);
+            }
+            Ok(Event::Eof) => break,
+            Err(_) => break,
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    out
+}
+
+/// Decodes XML's predefined entities, numeric character references, and
+/// the handful of HTML named entities (`&nbsp;` chief among them) that
+/// show up in real-world scraped markup but that quick_xml's own
+/// `unescape` doesn't know, since it only understands XML's 5 entities.
+/// An entity this doesn't recognize is left in the output verbatim
+/// rather than dropped.
+fn decode_entities(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    let mut rest = input;
+
+    while let Some(amp) = rest.find('&') {
+        out.push_str(&rest[..amp]);
+
+        match rest[amp..].find(';') {
+            Some(semi) => {
+                let entity = &rest[amp + 1..amp + semi];
+                match decode_entity(entity) {
+                    Some(ch) => out.push(ch),
+                    None => out.push_str(&rest[amp..amp + semi + 1]),
+                }
+                rest = &rest[amp + semi + 1..];
+            }
+            None => {
+                out.push_str(&rest[amp..]);
+                rest = "";
+            }
+        }
+    }
+    out.push_str(rest);
+
+    out
+}
+
+fn decode_entity(entity: &str) -> Option<char> {
+    match entity {
+        "amp" => Some('&'),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some('\u{00A0}'),
+        _ => {
+            let digits = entity
+                .strip_prefix("#x")
+                .or_else(|| entity.strip_prefix("#X"));
+            if let Some(hex) = digits {
+                u32::from_str_radix(hex, 16).ok().and_then(std::char::from_u32)
+            } else if let Some(dec) = entity.strip_prefix('#') {
+                dec.parse::<u32>().ok().and_then(std::char::from_u32)
+            } else {
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::strip_html;
+
+    #[test]
+    fn decodes_nbsp_and_amp_between_tags() {
+        let html = "<span>foo</span>&nbsp;|&nbsp;<span>bar &amp; baz</span>";
+        assert_eq!(strip_html(html), "foo | bar & baz");
+    }
+
+    #[test]
+    fn decodes_numeric_references() {
+        assert_eq!(strip_html("caf&#233; &#x2603;"), "caf\u{e9} \u{2603}");
+    }
+
+    #[test]
+    fn leaves_unknown_entities_verbatim() {
+        assert_eq!(strip_html("a &notareal; b"), "a &notareal; b");
+    }
+
+    #[test]
+    fn joins_sibling_text_fragments_with_a_space() {
+        let html = "<p>rank <a>title</a></p>";
+        assert_eq!(strip_html(html), "rank title");
+    }
+}